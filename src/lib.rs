@@ -1,11 +1,126 @@
 use std::num::ParseIntError;
 use thiserror::Error;
 
+mod palette;
+pub use palette::Palette;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
     pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    /// Повертає колір у вигляді канонічного hex-рядка (`#rrggbb`),
+    /// що згортається до `#rgb`, якщо ніблі кожного каналу збігаються.
+    /// Якщо колір не повністю непрозорий (`a != 255`), додається канал
+    /// альфа (`#rrggbbaa` або згорнуто `#rgba`).
+    pub fn to_hex(&self) -> String {
+        let collapses = |c: u8| (c >> 4) == (c & 0x0F);
+
+        if collapses(self.r) && collapses(self.g) && collapses(self.b) && collapses(self.a) {
+            if self.a == 255 {
+                format!("#{:x}{:x}{:x}", self.r & 0x0F, self.g & 0x0F, self.b & 0x0F)
+            } else {
+                format!(
+                    "#{:x}{:x}{:x}{:x}",
+                    self.r & 0x0F,
+                    self.g & 0x0F,
+                    self.b & 0x0F,
+                    self.a & 0x0F
+                )
+            }
+        } else if self.a == 255 {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+
+    /// Повертає колір у вигляді рядка `rgb(r, g, b)`, або `rgba(r, g, b, a)`,
+    /// якщо колір не повністю непрозорий (`a != 255`); `a` виражається як частка від 1.
+    pub fn to_rgb_string(&self) -> String {
+        if self.a == 255 {
+            format!("rgb({}, {}, {})", self.r, self.g, self.b)
+        } else {
+            format!(
+                "rgba({}, {}, {}, {})",
+                self.r,
+                self.g,
+                self.b,
+                alpha_to_fraction(self.a)
+            )
+        }
+    }
+
+    /// Повертає колір у вигляді рядка `hsl(h, s%, l%)`, обчисленого назад з `r`, `g`, `b`,
+    /// або `hsla(h, s%, l%, a)`, якщо колір не повністю непрозорий (`a != 255`).
+    pub fn to_hsl_string(&self) -> String {
+        let r = self.r as f32 / 255.0;
+        let g = self.g as f32 / 255.0;
+        let b = self.b as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let l = (max + min) / 2.0;
+
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+
+        let h_deg = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * (((g - b) / delta).rem_euclid(6.0))
+        } else if max == g {
+            60.0 * (((b - r) / delta) + 2.0)
+        } else {
+            60.0 * (((r - g) / delta) + 4.0)
+        };
+
+        if self.a == 255 {
+            format!(
+                "hsl({}, {}%, {}%)",
+                h_deg.round() as i32,
+                (s * 100.0).round() as i32,
+                (l * 100.0).round() as i32
+            )
+        } else {
+            format!(
+                "hsla({}, {}%, {}%, {})",
+                h_deg.round() as i32,
+                (s * 100.0).round() as i32,
+                (l * 100.0).round() as i32,
+                alpha_to_fraction(self.a)
+            )
+        }
+    }
+
+    /// Повертає ANSI truecolor escape-послідовність, що малює два пробіли
+    /// на тлі кольору `self` — зручний інлайн-зразок (swatch) для термінала.
+    pub fn ansi_truecolor_bg(&self) -> String {
+        format!("\x1b[48;2;{};{};{}m  \x1b[0m", self.r, self.g, self.b)
+    }
+
+    /// Повертає найближчий іменований CSS-колір за квадратом евклідової відстані в просторі RGB.
+    pub fn nearest_named(&self) -> &'static str {
+        NAMED_COLORS
+            .iter()
+            .min_by_key(|(_, c)| {
+                let dr = i32::from(self.r) - i32::from(c.r);
+                let dg = i32::from(self.g) - i32::from(c.g);
+                let db = i32::from(self.b) - i32::from(c.b);
+                dr * dr + dg * dg + db * db
+            })
+            .map(|(name, _)| *name)
+            .expect("NAMED_COLORS не може бути порожнім")
+    }
 }
 
 #[derive(Error, Debug)]
@@ -13,8 +128,8 @@ pub enum ColorParseError {
     #[error("Недійсна довжина Hex-коду: {0} символів. Очікується 3 або 6.")]
     InvalidLength(usize),
 
-    #[error("Недійсний шістнадцятковий компонент '{0}'. Деталі: {1}")]
-    ComponentParseError(String, #[source] ParseIntError),
+    #[error("Недійсний шістнадцятковий символ: '{0}'")]
+    InvalidHexChar(char),
 
     #[error("Колір має починатися з символу '#'. Непідтримуваний формат.")]
     MissingHashPrefix,
@@ -30,6 +145,18 @@ pub enum ColorParseError {
 
     #[error("Компонент rgb() поза діапазоном 0..=255: {0}")]
     RgbComponentOutOfRange(i32),
+
+    #[error("Альфа-канал поза діапазоном 0.0..=1.0: {0}")]
+    AlphaOutOfRange(f32),
+
+    #[error("Недійсний альфа-компонент: '{0}'")]
+    AlphaParseError(String),
+
+    #[error("Недійсний формат XParseColor. Очікується 'rgb:R/G/B' або '#' з кількістю символів, кратною трьом.")]
+    XParseInvalidFormat,
+
+    #[error("Недійсний компонент XParseColor '{0}'. Деталі: {1}")]
+    XParseComponentError(String, #[source] ParseIntError),
 }
 
 /// Перетворює рядкове представлення CSS-кольору у внутрішню структуру `Color`.
@@ -39,13 +166,19 @@ pub enum ColorParseError {
 /// Формальна граматика:
 ///
 /// ```text
-/// Color        := Named | Hex | Rgb | Hsl
-/// Named        := one of ["black", "white", "red", "green", "blue", "yellow", "cyan", "magenta", "gray", "grey", "rebeccapurple"]
-/// Hex          := "#" (Hex6 | Hex3)
+/// Color        := Named | Hex | Rgb | Hsl | XParseRgb | XParseLegacy
+/// Named        := one of the CSS Color Module Level 4 keywords (~148, напр. "aliceblue", "rebeccapurple", "transparent")
+/// Hex          := "#" (Hex8 | Hex6 | Hex4 | Hex3)
+/// Hex8         := H H H H H H H H             ; вісім шістнадцяткових символів (з альфа-каналом)
 /// Hex6         := H H H H H H                 ; шість шістнадцяткових символів
+/// Hex4         := H H H H                     ; чотири шістнадцяткових символи (дублюються, з альфа-каналом)
 /// Hex3         := H H H                       ; три шістнадцяткових символи (дублюються)
-/// Rgb          := "rgb" "(" Int "," Int "," Int ")"
-/// Hsl          := "hsl" "(" Int "," Int "%" "," Int "%" ")"
+/// Rgb          := ("rgb" | "rgba") "(" Int "," Int "," Int ["," Alpha] ")"
+/// Hsl          := ("hsl" | "hsla") "(" Int "," Int "%" "," Int "%" ["," Alpha] ")"
+/// Alpha        := Float                       ; дійсне число у діапазоні 0.0..=1.0
+/// XParseRgb    := "rgb:" HexN "/" HexN "/" HexN     ; X11 XParseColor, HexN = 1-4 символи
+/// XParseLegacy := "#" HexN HexN HexN                ; X11 legacy-форма, сумарна довжина кратна 3
+/// HexN         := H H? H? H?
 /// H            := [0-9A-Fa-f]
 /// Int          := Digit+                      ; десяткові цілі
 /// Digit        := [0-9]
@@ -64,8 +197,9 @@ pub enum ColorParseError {
 /// 3. **InvalidLength**:
 ///    - Будь-який рядок, що починається з `#`, але довжина частини без `#` **≠ 3** і **≠ 6**.
 ///
-/// 4. **InvalidChar / ComponentParseError**:
-///    - Будь-який Hex-код, що містить символи поза діапазоном `[0-9A-Fa-f]`.
+/// 4. **InvalidHexChar**:
+///    - Будь-який Hex-код, що містить символ поза діапазоном `[0-9A-Fa-f]` → `InvalidHexChar`
+///      із саме тим символом, який не вдалося розпізнати.
 ///
 /// 5. **RGB: `rgb(R, G, B)`**:
 ///    - Пробіли навколо чисел та ком дозволені.
@@ -84,22 +218,44 @@ pub enum ColorParseError {
 ///
 /// 8. **MissingHashPrefix**:
 ///    - Викидається, коли рядок не є іменованим кольором, не починається з `#` і не відповідає формі `rgb(...)` чи `hsl(...)`.
+///
+/// 9. **Альфа-канал (`rgba()` / `hsla()` / `#RRGGBBAA` / `#RGBA`)**:
+///    - Поле `a` за замовчуванням дорівнює `255` (непрозорий), якщо вхідний формат його не задає.
+///    - У `rgba(r, g, b, a)` та `hsla(h, s%, l%, a)` компонент `a` — дійсне число в діапазоні `0.0..=1.0`,
+///      що масштабується у `0..=255` через `(a * 255.0).round()`.
+///    - Нечислове значення `a` → `AlphaParseError`; значення поза `0.0..=1.0` → `AlphaOutOfRange`.
+///    - У `#RRGGBBAA` (8 символів) та `#RGBA` (4 символи) альфа-байт парситься як звичайний
+///      шістнадцятковий компонент, так само як `R`, `G` та `B`.
+///
+/// 10. **XParseColor (`rgb:R/G/B` та legacy `#...`)**:
+///    - Формат `XParseColor`, який емітують термінальні escape-послідовності (X11).
+///    - `rgb:RRRR/GGGG/BBBB`: текст після `rgb:` ділиться на 3 частини по `/`, кожна — 1-4
+///      шістнадцяткових символи. Компонент з `n` символів парситься як ціле `v` у `0..=16^n-1`,
+///      потім масштабується у 8 біт через `round(v * 255 / (16^n - 1))`.
+///    - Legacy-форма `#...`: кількість символів після `#` має ділитися на 3 без остачі
+///      (`#RGB`, `#RRGGBB`, `#RRRGGGBBB`, `#RRRRGGGGBBBB`, ...), кожна третина — один компонент,
+///      що масштабується так само, як у `rgb:`.
+///    - Форми з 3 та 6 символами після `#` обробляються як Hex3/Hex6 (правила 1, 2 і 4).
+///    - Невідповідна кількість компонентів у `rgb:` або компонент довжиною 0 чи понад 4
+///      символи → `XParseInvalidFormat`.
+///    - Довжина після `#`, не кратна 3 (і не 4 чи 8) → `InvalidLength`.
+///    - Недійсний шістнадцятковий символ у компоненті → `XParseComponentError`.
 /// ## Приклади
 ///
 /// ```
 /// use rcp_palette::{parse_color, Color};
 /// // Правило 1: Hex6
-/// assert_eq!(parse_color("#1A2B3C").unwrap(), Color { r: 26, g: 43, b: 60 });
+/// assert_eq!(parse_color("#1A2B3C").unwrap(), Color { r: 26, g: 43, b: 60, a: 255 });
 /// // Правило 2: Hex3
-/// assert_eq!(parse_color("#FA0").unwrap(), Color { r: 255, g: 170, b: 0 });
+/// assert_eq!(parse_color("#FA0").unwrap(), Color { r: 255, g: 170, b: 0, a: 255 });
 /// // Негативний приклад (Правило 3)
-/// assert!(parse_color("#1234").is_err());
+/// assert!(parse_color("#12345").is_err());
 /// // Негативний приклад (Правило 4)
 /// assert!(parse_color("#1A2B3G").is_err());
 /// // Правило 5: rgb() — валідний приклад
-/// assert_eq!(parse_color("rgb(255, 170, 0)").unwrap(), Color { r: 255, g: 170, b: 0 });
+/// assert_eq!(parse_color("rgb(255, 170, 0)").unwrap(), Color { r: 255, g: 170, b: 0, a: 255 });
 /// // Правило 5: rgb() — з пробілами
-/// assert_eq!(parse_color(" rgb( 26 , 43 , 60 ) ").unwrap(), Color { r: 26, g: 43, b: 60 });
+/// assert_eq!(parse_color(" rgb( 26 , 43 , 60 ) ").unwrap(), Color { r: 26, g: 43, b: 60, a: 255 });
 /// // Правило 5: rgb() — некоректний формат (замало компонентів)
 /// assert!(parse_color("rgb(255, 170)").is_err());
 /// // Правило 5: rgb() — вихід за діапазон 0..=255
@@ -107,11 +263,19 @@ pub enum ColorParseError {
 /// // Правило 5: rgb() — нечисловий компонент
 /// assert!(parse_color("rgb(aa, 0, 0)").is_err());
 /// // Правило 6: NamedColor (іменований колір)
-/// assert_eq!(parse_color("red").unwrap(), Color { r: 255, g: 0, b: 0 });
+/// assert_eq!(parse_color("red").unwrap(), Color { r: 255, g: 0, b: 0, a: 255 });
 /// // Правило 7: hsl() — червоний колір
-/// assert_eq!(parse_color("hsl(0, 100%, 50%)").unwrap(), Color { r: 255, g: 0, b: 0 });
+/// assert_eq!(parse_color("hsl(0, 100%, 50%)").unwrap(), Color { r: 255, g: 0, b: 0, a: 255 });
 /// // Негативний приклад (Правило 8: MissingHashPrefix)
 /// assert!(parse_color("1A2B3C").is_err());
+/// // Правило 9: rgba() — напівпрозорий колір
+/// assert_eq!(parse_color("rgba(255, 0, 0, 0.5)").unwrap(), Color { r: 255, g: 0, b: 0, a: 128 });
+/// // Правило 9: #RRGGBBAA
+/// assert_eq!(parse_color("#FF000080").unwrap(), Color { r: 255, g: 0, b: 0, a: 128 });
+/// // Правило 10: rgb:R/G/B
+/// assert_eq!(parse_color("rgb:ff/00/00").unwrap(), Color { r: 255, g: 0, b: 0, a: 255 });
+/// // Правило 10: legacy # форма (9 символів, по 3 на компонент)
+/// assert_eq!(parse_color("#fff000000").unwrap(), Color { r: 255, g: 0, b: 0, a: 255 });
 /// ```
 pub fn parse_color(input: &str) -> Result<Color, ColorParseError> {
     let trimmed = input.trim();
@@ -123,79 +287,306 @@ pub fn parse_color(input: &str) -> Result<Color, ColorParseError> {
     if let Some(hex_str) = trimmed.strip_prefix('#') {
         match hex_str.len() {
             3 => {
-                let mut chars = hex_str.chars();
-                let r_ch = chars.next().unwrap();
-                let g_ch = chars.next().unwrap();
-                let b_ch = chars.next().unwrap();
-
-                let r_str = format!("{0}{0}", r_ch);
-                let g_str = format!("{0}{0}", g_ch);
-                let b_str = format!("{0}{0}", b_ch);
-                let r = parse_component(&r_str)?;
-                let g = parse_component(&g_str)?;
-                let b = parse_component(&b_str)?;
-                Ok(Color { r, g, b })
+                let bytes = hex_str.as_bytes();
+                let r = parse_hex_pair(bytes[0], bytes[0])?;
+                let g = parse_hex_pair(bytes[1], bytes[1])?;
+                let b = parse_hex_pair(bytes[2], bytes[2])?;
+                Ok(Color { r, g, b, a: 255 })
+            }
+            4 => {
+                let bytes = hex_str.as_bytes();
+                let r = parse_hex_pair(bytes[0], bytes[0])?;
+                let g = parse_hex_pair(bytes[1], bytes[1])?;
+                let b = parse_hex_pair(bytes[2], bytes[2])?;
+                let a = parse_hex_pair(bytes[3], bytes[3])?;
+                Ok(Color { r, g, b, a })
             }
             6 => {
-                let r = parse_component(&hex_str[0..2])?;
-                let g = parse_component(&hex_str[2..4])?;
-                let b = parse_component(&hex_str[4..6])?;
-                Ok(Color { r, g, b })
+                let bytes = hex_str.as_bytes();
+                let r = parse_hex_pair(bytes[0], bytes[1])?;
+                let g = parse_hex_pair(bytes[2], bytes[3])?;
+                let b = parse_hex_pair(bytes[4], bytes[5])?;
+                Ok(Color { r, g, b, a: 255 })
+            }
+            8 => {
+                let bytes = hex_str.as_bytes();
+                let r = parse_hex_pair(bytes[0], bytes[1])?;
+                let g = parse_hex_pair(bytes[2], bytes[3])?;
+                let b = parse_hex_pair(bytes[4], bytes[5])?;
+                let a = parse_hex_pair(bytes[6], bytes[7])?;
+                Ok(Color { r, g, b, a })
             }
+            n if n > 0 && n % 3 == 0 => parse_x11_legacy_hex(hex_str),
             _ => Err(ColorParseError::InvalidLength(hex_str.len())),
         }
-    } else if trimmed.to_ascii_lowercase().starts_with("rgb(") {
+    } else if trimmed.to_ascii_lowercase().starts_with("rgb:") {
+        parse_x11_rgb(trimmed)
+    } else if trimmed.to_ascii_lowercase().starts_with("rgb(")
+        || trimmed.to_ascii_lowercase().starts_with("rgba(")
+    {
         parse_rgb(trimmed)
-    } else if trimmed.to_ascii_lowercase().starts_with("hsl(") {
+    } else if trimmed.to_ascii_lowercase().starts_with("hsl(")
+        || trimmed.to_ascii_lowercase().starts_with("hsla(")
+    {
         parse_hsl(trimmed)
     } else {
         Err(ColorParseError::MissingHashPrefix)
     }
 }
 
+/// Розбиває багаторядковий текст на рядки-кольори: обрізає пробіли з країв
+/// кожного рядка, відкидає порожні рядки та зберігає 0-based номер оригінального
+/// рядка. Спільна логіка для `Palette::from_lines` та `Commands::File` у CLI.
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+const NAMED_COLORS: &[(&str, Color)] = &[
+    ("aliceblue", Color { r: 240, g: 248, b: 255, a: 255 }),
+    ("antiquewhite", Color { r: 250, g: 235, b: 215, a: 255 }),
+    ("aqua", Color { r: 0, g: 255, b: 255, a: 255 }),
+    ("aquamarine", Color { r: 127, g: 255, b: 212, a: 255 }),
+    ("azure", Color { r: 240, g: 255, b: 255, a: 255 }),
+    ("beige", Color { r: 245, g: 245, b: 220, a: 255 }),
+    ("bisque", Color { r: 255, g: 228, b: 196, a: 255 }),
+    ("black", Color { r: 0, g: 0, b: 0, a: 255 }),
+    ("blanchedalmond", Color { r: 255, g: 235, b: 205, a: 255 }),
+    ("blue", Color { r: 0, g: 0, b: 255, a: 255 }),
+    ("blueviolet", Color { r: 138, g: 43, b: 226, a: 255 }),
+    ("brown", Color { r: 165, g: 42, b: 42, a: 255 }),
+    ("burlywood", Color { r: 222, g: 184, b: 135, a: 255 }),
+    ("cadetblue", Color { r: 95, g: 158, b: 160, a: 255 }),
+    ("chartreuse", Color { r: 127, g: 255, b: 0, a: 255 }),
+    ("chocolate", Color { r: 210, g: 105, b: 30, a: 255 }),
+    ("coral", Color { r: 255, g: 127, b: 80, a: 255 }),
+    ("cornflowerblue", Color { r: 100, g: 149, b: 237, a: 255 }),
+    ("cornsilk", Color { r: 255, g: 248, b: 220, a: 255 }),
+    ("crimson", Color { r: 220, g: 20, b: 60, a: 255 }),
+    ("cyan", Color { r: 0, g: 255, b: 255, a: 255 }),
+    ("darkblue", Color { r: 0, g: 0, b: 139, a: 255 }),
+    ("darkcyan", Color { r: 0, g: 139, b: 139, a: 255 }),
+    ("darkgoldenrod", Color { r: 184, g: 134, b: 11, a: 255 }),
+    ("darkgray", Color { r: 169, g: 169, b: 169, a: 255 }),
+    ("darkgreen", Color { r: 0, g: 100, b: 0, a: 255 }),
+    ("darkgrey", Color { r: 169, g: 169, b: 169, a: 255 }),
+    ("darkkhaki", Color { r: 189, g: 183, b: 107, a: 255 }),
+    ("darkmagenta", Color { r: 139, g: 0, b: 139, a: 255 }),
+    ("darkolivegreen", Color { r: 85, g: 107, b: 47, a: 255 }),
+    ("darkorange", Color { r: 255, g: 140, b: 0, a: 255 }),
+    ("darkorchid", Color { r: 153, g: 50, b: 204, a: 255 }),
+    ("darkred", Color { r: 139, g: 0, b: 0, a: 255 }),
+    ("darksalmon", Color { r: 233, g: 150, b: 122, a: 255 }),
+    ("darkseagreen", Color { r: 143, g: 188, b: 143, a: 255 }),
+    ("darkslateblue", Color { r: 72, g: 61, b: 139, a: 255 }),
+    ("darkslategray", Color { r: 47, g: 79, b: 79, a: 255 }),
+    ("darkslategrey", Color { r: 47, g: 79, b: 79, a: 255 }),
+    ("darkturquoise", Color { r: 0, g: 206, b: 209, a: 255 }),
+    ("darkviolet", Color { r: 148, g: 0, b: 211, a: 255 }),
+    ("deeppink", Color { r: 255, g: 20, b: 147, a: 255 }),
+    ("deepskyblue", Color { r: 0, g: 191, b: 255, a: 255 }),
+    ("dimgray", Color { r: 105, g: 105, b: 105, a: 255 }),
+    ("dimgrey", Color { r: 105, g: 105, b: 105, a: 255 }),
+    ("dodgerblue", Color { r: 30, g: 144, b: 255, a: 255 }),
+    ("firebrick", Color { r: 178, g: 34, b: 34, a: 255 }),
+    ("floralwhite", Color { r: 255, g: 250, b: 240, a: 255 }),
+    ("forestgreen", Color { r: 34, g: 139, b: 34, a: 255 }),
+    ("fuchsia", Color { r: 255, g: 0, b: 255, a: 255 }),
+    ("gainsboro", Color { r: 220, g: 220, b: 220, a: 255 }),
+    ("ghostwhite", Color { r: 248, g: 248, b: 255, a: 255 }),
+    ("gold", Color { r: 255, g: 215, b: 0, a: 255 }),
+    ("goldenrod", Color { r: 218, g: 165, b: 32, a: 255 }),
+    ("gray", Color { r: 128, g: 128, b: 128, a: 255 }),
+    ("green", Color { r: 0, g: 128, b: 0, a: 255 }),
+    ("greenyellow", Color { r: 173, g: 255, b: 47, a: 255 }),
+    ("grey", Color { r: 128, g: 128, b: 128, a: 255 }),
+    ("honeydew", Color { r: 240, g: 255, b: 240, a: 255 }),
+    ("hotpink", Color { r: 255, g: 105, b: 180, a: 255 }),
+    ("indianred", Color { r: 205, g: 92, b: 92, a: 255 }),
+    ("indigo", Color { r: 75, g: 0, b: 130, a: 255 }),
+    ("ivory", Color { r: 255, g: 255, b: 240, a: 255 }),
+    ("khaki", Color { r: 240, g: 230, b: 140, a: 255 }),
+    ("lavender", Color { r: 230, g: 230, b: 250, a: 255 }),
+    ("lavenderblush", Color { r: 255, g: 240, b: 245, a: 255 }),
+    ("lawngreen", Color { r: 124, g: 252, b: 0, a: 255 }),
+    ("lemonchiffon", Color { r: 255, g: 250, b: 205, a: 255 }),
+    ("lightblue", Color { r: 173, g: 216, b: 230, a: 255 }),
+    ("lightcoral", Color { r: 240, g: 128, b: 128, a: 255 }),
+    ("lightcyan", Color { r: 224, g: 255, b: 255, a: 255 }),
+    ("lightgoldenrodyellow", Color { r: 250, g: 250, b: 210, a: 255 }),
+    ("lightgray", Color { r: 211, g: 211, b: 211, a: 255 }),
+    ("lightgreen", Color { r: 144, g: 238, b: 144, a: 255 }),
+    ("lightgrey", Color { r: 211, g: 211, b: 211, a: 255 }),
+    ("lightpink", Color { r: 255, g: 182, b: 193, a: 255 }),
+    ("lightsalmon", Color { r: 255, g: 160, b: 122, a: 255 }),
+    ("lightseagreen", Color { r: 32, g: 178, b: 170, a: 255 }),
+    ("lightskyblue", Color { r: 135, g: 206, b: 250, a: 255 }),
+    ("lightslategray", Color { r: 119, g: 136, b: 153, a: 255 }),
+    ("lightslategrey", Color { r: 119, g: 136, b: 153, a: 255 }),
+    ("lightsteelblue", Color { r: 176, g: 196, b: 222, a: 255 }),
+    ("lightyellow", Color { r: 255, g: 255, b: 224, a: 255 }),
+    ("lime", Color { r: 0, g: 255, b: 0, a: 255 }),
+    ("limegreen", Color { r: 50, g: 205, b: 50, a: 255 }),
+    ("linen", Color { r: 250, g: 240, b: 230, a: 255 }),
+    ("magenta", Color { r: 255, g: 0, b: 255, a: 255 }),
+    ("maroon", Color { r: 128, g: 0, b: 0, a: 255 }),
+    ("mediumaquamarine", Color { r: 102, g: 205, b: 170, a: 255 }),
+    ("mediumblue", Color { r: 0, g: 0, b: 205, a: 255 }),
+    ("mediumorchid", Color { r: 186, g: 85, b: 211, a: 255 }),
+    ("mediumpurple", Color { r: 147, g: 112, b: 219, a: 255 }),
+    ("mediumseagreen", Color { r: 60, g: 179, b: 113, a: 255 }),
+    ("mediumslateblue", Color { r: 123, g: 104, b: 238, a: 255 }),
+    ("mediumspringgreen", Color { r: 0, g: 250, b: 154, a: 255 }),
+    ("mediumturquoise", Color { r: 72, g: 209, b: 204, a: 255 }),
+    ("mediumvioletred", Color { r: 199, g: 21, b: 133, a: 255 }),
+    ("midnightblue", Color { r: 25, g: 25, b: 112, a: 255 }),
+    ("mintcream", Color { r: 245, g: 255, b: 250, a: 255 }),
+    ("mistyrose", Color { r: 255, g: 228, b: 225, a: 255 }),
+    ("moccasin", Color { r: 255, g: 228, b: 181, a: 255 }),
+    ("navajowhite", Color { r: 255, g: 222, b: 173, a: 255 }),
+    ("navy", Color { r: 0, g: 0, b: 128, a: 255 }),
+    ("oldlace", Color { r: 253, g: 245, b: 230, a: 255 }),
+    ("olive", Color { r: 128, g: 128, b: 0, a: 255 }),
+    ("olivedrab", Color { r: 107, g: 142, b: 35, a: 255 }),
+    ("orange", Color { r: 255, g: 165, b: 0, a: 255 }),
+    ("orangered", Color { r: 255, g: 69, b: 0, a: 255 }),
+    ("orchid", Color { r: 218, g: 112, b: 214, a: 255 }),
+    ("palegoldenrod", Color { r: 238, g: 232, b: 170, a: 255 }),
+    ("palegreen", Color { r: 152, g: 251, b: 152, a: 255 }),
+    ("paleturquoise", Color { r: 175, g: 238, b: 238, a: 255 }),
+    ("palevioletred", Color { r: 219, g: 112, b: 147, a: 255 }),
+    ("papayawhip", Color { r: 255, g: 239, b: 213, a: 255 }),
+    ("peachpuff", Color { r: 255, g: 218, b: 185, a: 255 }),
+    ("peru", Color { r: 205, g: 133, b: 63, a: 255 }),
+    ("pink", Color { r: 255, g: 192, b: 203, a: 255 }),
+    ("plum", Color { r: 221, g: 160, b: 221, a: 255 }),
+    ("powderblue", Color { r: 176, g: 224, b: 230, a: 255 }),
+    ("purple", Color { r: 128, g: 0, b: 128, a: 255 }),
+    ("rebeccapurple", Color { r: 102, g: 51, b: 153, a: 255 }),
+    ("red", Color { r: 255, g: 0, b: 0, a: 255 }),
+    ("rosybrown", Color { r: 188, g: 143, b: 143, a: 255 }),
+    ("royalblue", Color { r: 65, g: 105, b: 225, a: 255 }),
+    ("saddlebrown", Color { r: 139, g: 69, b: 19, a: 255 }),
+    ("salmon", Color { r: 250, g: 128, b: 114, a: 255 }),
+    ("sandybrown", Color { r: 244, g: 164, b: 96, a: 255 }),
+    ("seagreen", Color { r: 46, g: 139, b: 87, a: 255 }),
+    ("seashell", Color { r: 255, g: 245, b: 238, a: 255 }),
+    ("sienna", Color { r: 160, g: 82, b: 45, a: 255 }),
+    ("silver", Color { r: 192, g: 192, b: 192, a: 255 }),
+    ("skyblue", Color { r: 135, g: 206, b: 235, a: 255 }),
+    ("slateblue", Color { r: 106, g: 90, b: 205, a: 255 }),
+    ("slategray", Color { r: 112, g: 128, b: 144, a: 255 }),
+    ("slategrey", Color { r: 112, g: 128, b: 144, a: 255 }),
+    ("snow", Color { r: 255, g: 250, b: 250, a: 255 }),
+    ("springgreen", Color { r: 0, g: 255, b: 127, a: 255 }),
+    ("steelblue", Color { r: 70, g: 130, b: 180, a: 255 }),
+    ("tan", Color { r: 210, g: 180, b: 140, a: 255 }),
+    ("teal", Color { r: 0, g: 128, b: 128, a: 255 }),
+    ("thistle", Color { r: 216, g: 191, b: 216, a: 255 }),
+    ("tomato", Color { r: 255, g: 99, b: 71, a: 255 }),
+    ("transparent", Color { r: 0, g: 0, b: 0, a: 0 }),
+    ("turquoise", Color { r: 64, g: 224, b: 208, a: 255 }),
+    ("violet", Color { r: 238, g: 130, b: 238, a: 255 }),
+    ("wheat", Color { r: 245, g: 222, b: 179, a: 255 }),
+    ("white", Color { r: 255, g: 255, b: 255, a: 255 }),
+    ("whitesmoke", Color { r: 245, g: 245, b: 245, a: 255 }),
+    ("yellow", Color { r: 255, g: 255, b: 0, a: 255 }),
+    ("yellowgreen", Color { r: 154, g: 205, b: 50, a: 255 }),
+];
+
+
 fn parse_named_color(name: &str) -> Option<Color> {
-    match name.to_ascii_lowercase().as_str() {
-        "black" => Some(Color { r: 0, g: 0, b: 0 }),
-        "white" => Some(Color {
-            r: 255,
-            g: 255,
-            b: 255,
-        }),
-        "red" => Some(Color { r: 255, g: 0, b: 0 }),
-        "green" => Some(Color { r: 0, g: 128, b: 0 }),
-        "blue" => Some(Color { r: 0, g: 0, b: 255 }),
-        "yellow" => Some(Color {
-            r: 255,
-            g: 255,
-            b: 0,
-        }),
-        "cyan" => Some(Color {
-            r: 0,
-            g: 255,
-            b: 255,
-        }),
-        "magenta" => Some(Color {
-            r: 255,
-            g: 0,
-            b: 255,
-        }),
-        "gray" | "grey" => Some(Color {
-            r: 128,
-            g: 128,
-            b: 128,
-        }),
-        "rebeccapurple" => Some(Color {
-            r: 102,
-            g: 51,
-            b: 153,
-        }),
-        _ => None,
+    let lower = name.to_ascii_lowercase();
+    NAMED_COLORS
+        .binary_search_by(|(candidate, _)| candidate.cmp(&lower.as_str()))
+        .ok()
+        .map(|idx| NAMED_COLORS[idx].1)
+}
+
+const fn hex_nibble(c: u8) -> Result<u8, u8> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(c),
+    }
+}
+
+const fn hex_byte(hi: u8, lo: u8) -> Result<u8, u8> {
+    let hi = match hex_nibble(hi) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    let lo = match hex_nibble(lo) {
+        Ok(v) => v,
+        Err(e) => return Err(e),
+    };
+    Ok((hi << 4) | lo)
+}
+
+fn parse_hex_pair(hi: u8, lo: u8) -> Result<u8, ColorParseError> {
+    hex_byte(hi, lo).map_err(|c| ColorParseError::InvalidHexChar(c as char))
+}
+
+/// Зворотне перетворення байта альфа-каналу у частку `0.0..=1.0` (округлену до
+/// сотих), як очікують `rgba()`/`hsla()`.
+fn alpha_to_fraction(a: u8) -> f32 {
+    ((a as f32 / 255.0) * 100.0).round() / 100.0
+}
+
+fn parse_alpha_component(component: &str) -> Result<u8, ColorParseError> {
+    let value: f32 = component
+        .parse()
+        .map_err(|_| ColorParseError::AlphaParseError(component.to_string()))?;
+
+    if !(0.0..=1.0).contains(&value) {
+        return Err(ColorParseError::AlphaOutOfRange(value));
+    }
+
+    Ok((value * 255.0).round() as u8)
+}
+
+fn parse_x11_component(component: &str) -> Result<u8, ColorParseError> {
+    if component.is_empty() || component.len() > 4 {
+        return Err(ColorParseError::XParseInvalidFormat);
     }
+
+    let v = u32::from_str_radix(component, 16)
+        .map_err(|e| ColorParseError::XParseComponentError(component.to_string(), e))?;
+    let max = 16u32.pow(component.len() as u32) - 1;
+
+    Ok((v as f64 * 255.0 / max as f64).round() as u8)
 }
 
-fn parse_component(component: &str) -> Result<u8, ColorParseError> {
-    u8::from_str_radix(component, 16)
-        .map_err(|e| ColorParseError::ComponentParseError(component.to_string(), e))
+fn parse_x11_rgb(input: &str) -> Result<Color, ColorParseError> {
+    let rest = &input["rgb:".len()..];
+    let parts: Vec<&str> = rest.split('/').collect();
+
+    if parts.len() != 3 {
+        return Err(ColorParseError::XParseInvalidFormat);
+    }
+
+    let r = parse_x11_component(parts[0])?;
+    let g = parse_x11_component(parts[1])?;
+    let b = parse_x11_component(parts[2])?;
+
+    Ok(Color { r, g, b, a: 255 })
+}
+
+fn parse_x11_legacy_hex(hex_str: &str) -> Result<Color, ColorParseError> {
+    if !hex_str.is_ascii() {
+        return Err(ColorParseError::XParseInvalidFormat);
+    }
+
+    let n = hex_str.len() / 3;
+    let r = parse_x11_component(&hex_str[0..n])?;
+    let g = parse_x11_component(&hex_str[n..2 * n])?;
+    let b = parse_x11_component(&hex_str[2 * n..3 * n])?;
+
+    Ok(Color { r, g, b, a: 255 })
 }
 
 fn parse_rgb(input: &str) -> Result<Color, ColorParseError> {
@@ -208,12 +599,12 @@ fn parse_rgb(input: &str) -> Result<Color, ColorParseError> {
         let inner = &input[open + 1..close];
         let parts: Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
 
-        if parts.len() != 3 {
+        if parts.len() != 3 && parts.len() != 4 {
             Err(ColorParseError::RgbInvalidFormat)
         } else {
             let mut nums = [0u8; 3];
 
-            for (i, p) in parts.iter().enumerate() {
+            for (i, p) in parts[..3].iter().enumerate() {
                 let parsed: i32 = p
                     .parse()
                     .map_err(|_| ColorParseError::RgbComponentParseError((*p).to_string()))?;
@@ -223,10 +614,17 @@ fn parse_rgb(input: &str) -> Result<Color, ColorParseError> {
                 nums[i] = parsed as u8;
             }
 
+            let a = if parts.len() == 4 {
+                parse_alpha_component(parts[3])?
+            } else {
+                255
+            };
+
             Ok(Color {
                 r: nums[0],
                 g: nums[1],
                 b: nums[2],
+                a,
             })
         }
     }
@@ -242,10 +640,16 @@ fn parse_hsl(input: &str) -> Result<Color, ColorParseError> {
 
     let inner = &input[open + 1..close];
     let parts: Vec<&str> = inner.split(',').map(|s| s.trim()).collect();
-    if parts.len() != 3 {
+    if parts.len() != 3 && parts.len() != 4 {
         return Err(ColorParseError::UnsupportedFormat);
     }
 
+    let a = if parts.len() == 4 {
+        parse_alpha_component(parts[3])?
+    } else {
+        255
+    };
+
     let h_deg: f32 = parts[0]
         .parse()
         .map_err(|_| ColorParseError::UnsupportedFormat)?;
@@ -301,5 +705,5 @@ fn parse_hsl(input: &str) -> Result<Color, ColorParseError> {
     let g = (g_f * 255.0).round().clamp(0.0, 255.0) as u8;
     let b = (b_f * 255.0).round().clamp(0.0, 255.0) as u8;
 
-    Ok(Color { r, g, b })
+    Ok(Color { r, g, b, a })
 }
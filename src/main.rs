@@ -1,8 +1,31 @@
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand};
-use rcp_palette::{parse_color, ColorParseError};
+use clap::{Parser, Subcommand, ValueEnum};
+use rcp_palette::{non_blank_lines, parse_color, ColorParseError, Palette};
+use std::io::IsTerminal;
 use std::fs;
 
+#[derive(ValueEnum, Clone, Debug)]
+enum OutputFormat {
+    Hex,
+    Rgb,
+    Hsl,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+fn show_swatches(mode: ColorMode) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => std::io::stdout().is_terminal(),
+    }
+}
+
 #[derive(Parser, Debug)]
 #[clap(
     author,
@@ -13,17 +36,45 @@ use std::fs;
 struct Cli {
     #[clap(subcommand)]
     command: Commands,
+
+    /// Керує виводом ANSI-зразків кольору (truecolor swatch): auto вимикає їх,
+    /// якщо stdout не є терміналом.
+    #[clap(long, value_enum, default_value = "auto", global = true)]
+    color: ColorMode,
 }
 
 #[derive(Subcommand, Debug)]
 enum Commands {
     Parse { color_str: String },
     File { path: String },
+    Format {
+        color_str: String,
+        #[clap(value_enum)]
+        r#as: OutputFormat,
+    },
+    Palette {
+        #[clap(subcommand)]
+        scheme: PaletteScheme,
+    },
     Author,
 }
 
+#[derive(Subcommand, Debug)]
+enum PaletteScheme {
+    Rainbow { n: usize },
+    Gradient {
+        start: String,
+        end: String,
+        n: usize,
+    },
+    File {
+        path: String,
+    },
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    let show_color = show_swatches(cli.color);
 
     match &cli.command {
         Commands::Parse { color_str } => {
@@ -35,28 +86,38 @@ fn main() -> Result<()> {
 
             println!("Парсинг кольору пройшов успішно!");
             println!("   > Введений колір: {}", color_str);
-            println!("   > Color: r: {}, g: {}, b: {}", color.r, color.g, color.b);
+            println!(
+                "   > Color: r: {}, g: {}, b: {}, a: {}",
+                color.r, color.g, color.b, color.a
+            );
+            if show_color {
+                println!("   > Зразок: {}", color.ansi_truecolor_bg());
+            }
         }
         Commands::File { path } => {
             println!("--- Читання та парсинг кольорів з файлу: {} ---", path);
 
             let content = fs::read_to_string(path)
                 .with_context(|| format!("Не вдалося прочитати файл за шляхом: {}", path))?;
-            for (i, line) in content.lines().enumerate() {
-                let trimmed_line = line.trim();
-                if trimmed_line.is_empty() {
-                    continue;
-                }
-
+            for (i, trimmed_line) in non_blank_lines(&content) {
                 match parse_color(trimmed_line) {
-                    Ok(color) => println!(
-                        "Рядок {}: ✅ {} -> RGB: r:{}, g:{}, b:{}",
-                        i + 1,
-                        trimmed_line,
-                        color.r,
-                        color.g,
-                        color.b
-                    ),
+                    Ok(color) => {
+                        let swatch = if show_color {
+                            format!("{} ", color.ansi_truecolor_bg())
+                        } else {
+                            String::new()
+                        };
+                        println!(
+                            "Рядок {}: {}✅ {} -> RGB: r:{}, g:{}, b:{}, a:{}",
+                            i + 1,
+                            swatch,
+                            trimmed_line,
+                            color.r,
+                            color.g,
+                            color.b,
+                            color.a
+                        )
+                    }
                     Err(e) => match e {
                         ColorParseError::MissingHashPrefix => eprintln!(
                             "Рядок {}: ❌ {} -> Помилка: Колір має починатися з '#'",
@@ -68,12 +129,63 @@ fn main() -> Result<()> {
                             i + 1,
                             trimmed_line
                         ),
+                        ColorParseError::AlphaOutOfRange(_) | ColorParseError::AlphaParseError(_) => {
+                            eprintln!(
+                                "Рядок {}: ❌ {} -> Помилка: Недійсний альфа-канал",
+                                i + 1,
+                                trimmed_line
+                            )
+                        }
+                        ColorParseError::InvalidHexChar(ch) => eprintln!(
+                            "Рядок {}: ❌ {} -> Помилка: Недійсний символ у Hex-коді: '{}'",
+                            i + 1,
+                            trimmed_line,
+                            ch
+                        ),
                         _ => eprintln!("Рядок {}: ❌ {} -> Помилка: {}", i + 1, trimmed_line, e),
                     },
                 }
             }
             println!("--- Парсинг файлу завершено ---");
         }
+        Commands::Format { color_str, r#as } => {
+            let color = parse_color(color_str)
+                .map_err(|e| anyhow::anyhow!(e))
+                .context("Не вдалося виконати парсинг кольору!")?;
+
+            let output = match r#as {
+                OutputFormat::Hex => color.to_hex(),
+                OutputFormat::Rgb => color.to_rgb_string(),
+                OutputFormat::Hsl => color.to_hsl_string(),
+            };
+
+            println!("{}", output);
+        }
+        Commands::Palette { scheme } => {
+            let palette = match scheme {
+                PaletteScheme::Rainbow { n } => Palette::rainbow(*n),
+                PaletteScheme::Gradient { start, end, n } => {
+                    let start = parse_color(start)
+                        .map_err(|e| anyhow::anyhow!(e))
+                        .context("Не вдалося виконати парсинг початкового кольору!")?;
+                    let end = parse_color(end)
+                        .map_err(|e| anyhow::anyhow!(e))
+                        .context("Не вдалося виконати парсинг кінцевого кольору!")?;
+                    Palette::gradient(start, end, *n)
+                }
+                PaletteScheme::File { path } => {
+                    let content = fs::read_to_string(path)
+                        .with_context(|| format!("Не вдалося прочитати файл за шляхом: {}", path))?;
+                    Palette::from_lines(&content)
+                        .map_err(|e| anyhow::anyhow!(e))
+                        .context("Не вдалося виконати парсинг палітри з файлу!")?
+                }
+            };
+
+            for color in palette.colors() {
+                println!("{}", color.to_hex());
+            }
+        }
         Commands::Author => {
             println!("--- 🎨 rcp-palette (CSS Color Parser) ---");
             println!("Автор: {}", env!("CARGO_PKG_AUTHORS"));
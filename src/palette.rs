@@ -0,0 +1,75 @@
+use crate::{non_blank_lines, parse_color, Color, ColorParseError};
+
+/// Впорядкований набір кольорів: обгортка над `Vec<Color>`, що дозволяє
+/// генерувати та циклічно перебирати кольорові схеми (веселка, градієнт, файл).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+impl Palette {
+    /// Повертає кольори, з яких складається палітра.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Генерує `n` кольорів веселки: рівномірно розподілені відтінки (hue)
+    /// через наявний шлях HSL -> RGB при фіксованих насиченості (100%) та яскравості (50%).
+    pub fn rainbow(n: usize) -> Self {
+        let colors = (0..n)
+            .map(|i| {
+                let hue = (i as f32 * 360.0 / n as f32).round() as i32;
+                let hsl = format!("hsl({}, 100%, 50%)", hue);
+                parse_color(&hsl).expect("згенерований hsl() завжди валідний")
+            })
+            .collect();
+
+        Palette { colors }
+    }
+
+    /// Генерує палітру з `n` кольорів, лінійно інтерполюючи кожен канал
+    /// (`r`, `g`, `b`, `a`) між `start` та `end`.
+    pub fn gradient(start: Color, end: Color, n: usize) -> Self {
+        let lerp = |from: u8, to: u8, t: f32| {
+            (from as f32 + (to as f32 - from as f32) * t).round() as u8
+        };
+
+        let colors = (0..n)
+            .map(|i| {
+                let t = if n <= 1 {
+                    0.0
+                } else {
+                    i as f32 / (n - 1) as f32
+                };
+
+                Color {
+                    r: lerp(start.r, end.r, t),
+                    g: lerp(start.g, end.g, t),
+                    b: lerp(start.b, end.b, t),
+                    a: lerp(start.a, end.a, t),
+                }
+            })
+            .collect();
+
+        Palette { colors }
+    }
+
+    /// Будує палітру, парсячи кожен непорожній рядок тексту через `parse_color`.
+    pub fn from_lines(input: &str) -> Result<Self, ColorParseError> {
+        let colors = non_blank_lines(input)
+            .map(|(_, line)| parse_color(line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Palette { colors })
+    }
+
+    /// Повертає колір за індексом, що циклічно повторюється через модуль довжини палітри.
+    ///
+    /// # Паніка
+    ///
+    /// Панікує, якщо палітра порожня — циклічний перебір порожньої множини не визначений.
+    pub fn cycle(&self, i: usize) -> Color {
+        assert!(!self.colors.is_empty(), "Palette::cycle: палітра порожня");
+        self.colors[i % self.colors.len()]
+    }
+}
@@ -17,7 +17,7 @@ fn hex3_parsing_is_ok() -> Result<()> {
 
 #[test]
 fn invalid_length_is_error() -> Result<()> {
-    let err = parse_color("#1234").unwrap_err();
+    let err = parse_color("#12345").unwrap_err();
     let msg = err.to_string();
     assert!(msg.contains("Недійсна довжина") || msg.contains("Invalid"));
     Ok(())
@@ -28,6 +28,7 @@ fn invalid_char_is_error() -> Result<()> {
     let err = parse_color("#1A2B3G").unwrap_err();
     let msg = err.to_string();
     assert!(msg.contains("Недійсний") || msg.contains("invalid"));
+    assert!(msg.contains('G'));
     Ok(())
 }
 
@@ -76,3 +77,176 @@ fn rgb_non_numeric_component_is_error() -> Result<()> {
     assert!(msg.contains("числовий") || msg.contains("numeric"));
     Ok(())
 }
+
+#[test]
+fn rgba_parsing_is_ok() -> Result<()> {
+    let c = parse_color("rgba(255, 0, 0, 0.5)")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (255, 0, 0, 128));
+    Ok(())
+}
+
+#[test]
+fn hsla_parsing_is_ok() -> Result<()> {
+    let c = parse_color("hsla(0, 100%, 50%, 0.5)")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (255, 0, 0, 128));
+    Ok(())
+}
+
+#[test]
+fn hex8_parsing_is_ok() -> Result<()> {
+    let c = parse_color("#FF000080")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (255, 0, 0, 128));
+    Ok(())
+}
+
+#[test]
+fn hex4_parsing_is_ok() -> Result<()> {
+    let c = parse_color("#F008")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (255, 0, 0, 136));
+    Ok(())
+}
+
+#[test]
+fn alpha_out_of_range_is_error() -> Result<()> {
+    let err = parse_color("rgba(255, 0, 0, 1.5)").unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("Альфа") || msg.contains("alpha") || msg.contains("Alpha"));
+    Ok(())
+}
+
+#[test]
+fn default_alpha_is_opaque() -> Result<()> {
+    let c = parse_color("#1A2B3C")?;
+    assert_eq!(c.a, 255);
+    Ok(())
+}
+
+#[test]
+fn x11_rgb_colon_parsing_is_ok() -> Result<()> {
+    let c = parse_color("rgb:ff/00/00")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (255, 0, 0, 255));
+    Ok(())
+}
+
+#[test]
+fn x11_rgb_colon_variable_width_is_ok() -> Result<()> {
+    let c = parse_color("rgb:f/0/0")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (255, 0, 0, 255));
+    Ok(())
+}
+
+#[test]
+fn x11_legacy_hex_parsing_is_ok() -> Result<()> {
+    let c = parse_color("#fff000000")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (255, 0, 0, 255));
+    Ok(())
+}
+
+#[test]
+fn x11_legacy_hex_non_ascii_is_error_not_panic() -> Result<()> {
+    let err = parse_color("#aaéaaaaa").unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("XParseColor"));
+    Ok(())
+}
+
+#[test]
+fn x11_rgb_colon_invalid_component_count_is_error() -> Result<()> {
+    let err = parse_color("rgb:ff/00").unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("XParseColor"));
+    Ok(())
+}
+
+#[test]
+fn to_hex_collapses_to_short_form() -> Result<()> {
+    let c = parse_color("#FFAA00")?;
+    assert_eq!(c.to_hex(), "#fa0");
+    Ok(())
+}
+
+#[test]
+fn to_hex_stays_long_form() -> Result<()> {
+    let c = parse_color("#1A2B3C")?;
+    assert_eq!(c.to_hex(), "#1a2b3c");
+    Ok(())
+}
+
+#[test]
+fn to_rgb_string_round_trips() -> Result<()> {
+    let c = parse_color("#1A2B3C")?;
+    assert_eq!(c.to_rgb_string(), "rgb(26, 43, 60)");
+    Ok(())
+}
+
+#[test]
+fn to_hsl_string_round_trips_red() -> Result<()> {
+    let c = parse_color("red")?;
+    assert_eq!(c.to_hsl_string(), "hsl(0, 100%, 50%)");
+    Ok(())
+}
+
+#[test]
+fn to_hex_includes_alpha_when_not_opaque() -> Result<()> {
+    let c = parse_color("rgba(255, 0, 0, 0.5)")?;
+    assert_eq!(c.to_hex(), "#ff000080");
+    Ok(())
+}
+
+#[test]
+fn to_hex_collapses_short_form_with_alpha() -> Result<()> {
+    let c = parse_color("#F008")?;
+    assert_eq!(c.to_hex(), "#f008");
+    Ok(())
+}
+
+#[test]
+fn to_rgb_string_includes_alpha_when_not_opaque() -> Result<()> {
+    let c = parse_color("rgba(255, 0, 0, 0.5)")?;
+    assert_eq!(c.to_rgb_string(), "rgba(255, 0, 0, 0.5)");
+    Ok(())
+}
+
+#[test]
+fn to_hsl_string_includes_alpha_when_not_opaque() -> Result<()> {
+    let c = parse_color("hsla(0, 100%, 50%, 0.5)")?;
+    assert_eq!(c.to_hsl_string(), "hsla(0, 100%, 50%, 0.5)");
+    Ok(())
+}
+
+#[test]
+fn extended_named_color_is_ok() -> Result<()> {
+    let c = parse_color("rebeccapurple")?;
+    assert_eq!((c.r, c.g, c.b), (102, 51, 153));
+    let c = parse_color("aliceblue")?;
+    assert_eq!((c.r, c.g, c.b), (240, 248, 255));
+    Ok(())
+}
+
+#[test]
+fn transparent_named_color_has_zero_alpha() -> Result<()> {
+    let c = parse_color("transparent")?;
+    assert_eq!((c.r, c.g, c.b, c.a), (0, 0, 0, 0));
+    Ok(())
+}
+
+#[test]
+fn nearest_named_finds_exact_match() -> Result<()> {
+    let c = parse_color("#FF0000")?;
+    assert_eq!(c.nearest_named(), "red");
+    Ok(())
+}
+
+#[test]
+fn nearest_named_finds_closest_match() -> Result<()> {
+    let c = parse_color("#FE0001")?;
+    assert_eq!(c.nearest_named(), "red");
+    Ok(())
+}
+
+#[test]
+fn ansi_truecolor_bg_wraps_rgb_in_escape_sequence() -> Result<()> {
+    let c = parse_color("#1A2B3C")?;
+    assert_eq!(c.ansi_truecolor_bg(), "\x1b[48;2;26;43;60m  \x1b[0m");
+    Ok(())
+}
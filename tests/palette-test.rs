@@ -0,0 +1,55 @@
+use anyhow::Result;
+use rcp_palette::{parse_color, Palette};
+
+#[test]
+fn rainbow_generates_n_colors() -> Result<()> {
+    let palette = Palette::rainbow(6);
+    assert_eq!(palette.colors().len(), 6);
+    Ok(())
+}
+
+#[test]
+fn rainbow_first_hue_is_red() -> Result<()> {
+    let palette = Palette::rainbow(4);
+    assert_eq!(palette.colors()[0], parse_color("hsl(0, 100%, 50%)")?);
+    Ok(())
+}
+
+#[test]
+fn gradient_interpolates_endpoints() -> Result<()> {
+    let start = parse_color("#000000")?;
+    let end = parse_color("#FFFFFF")?;
+    let palette = Palette::gradient(start, end, 3);
+
+    assert_eq!(palette.colors()[0], start);
+    assert_eq!(palette.colors()[2], end);
+    assert_eq!((palette.colors()[1].r, palette.colors()[1].g, palette.colors()[1].b), (128, 128, 128));
+    Ok(())
+}
+
+#[test]
+fn from_lines_skips_blank_lines() -> Result<()> {
+    let palette = Palette::from_lines("red\n\n#00FF00\n  \nblue")?;
+    assert_eq!(palette.colors().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn from_lines_propagates_parse_error() -> Result<()> {
+    assert!(Palette::from_lines("red\nnotacolor").is_err());
+    Ok(())
+}
+
+#[test]
+fn cycle_wraps_around() -> Result<()> {
+    let palette = Palette::from_lines("red\ngreen\nblue")?;
+    assert_eq!(palette.cycle(0), palette.cycle(3));
+    assert_eq!(palette.cycle(1), palette.cycle(4));
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "палітра порожня")]
+fn cycle_panics_on_empty_palette() {
+    Palette::rainbow(0).cycle(0);
+}